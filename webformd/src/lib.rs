@@ -1,8 +1,16 @@
 // In your `oursistem` or shared library crate.
 pub trait WebFomData: Sized {
     /// Deserializes a struct from a `Vec<(String, String)>`.
+    #[allow(clippy::ptr_arg)] // part of the derive's public signature; changing it breaks callers
     fn deserialize(data: &Vec<(String, String)>) -> Result<Self, String>;
 }
 
-// Re-export the macro from the other crate
-pub use webformd_macros::WebformDeserialize;
\ No newline at end of file
+/// The inverse of [`WebFomData`]: turns a struct back into form/query pairs.
+pub trait WebFormSerialize {
+    /// Serializes a struct into a `Vec<(String, String)>`.
+    fn serialize(&self) -> Vec<(String, String)>;
+}
+
+// Re-export the macros from the other crate
+pub use webformd_macros::WebformDeserialize;
+pub use webformd_macros::WebformSerialize;
\ No newline at end of file
@@ -0,0 +1,168 @@
+use webformd::{WebFomData, WebFormSerialize, WebformDeserialize, WebformSerialize};
+
+fn pairs(data: &[(&str, &str)]) -> Vec<(String, String)> {
+    data.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, WebformDeserialize)]
+struct Contact {
+    #[webformd(rename = "user-email", alias = "email_address")]
+    email: String,
+    name: String,
+}
+
+#[test]
+fn rename_accepts_the_renamed_key() {
+    let data = pairs(&[("user-email", "a@example.com"), ("name", "Ada")]);
+    let contact = Contact::deserialize(&data).unwrap();
+    assert_eq!(contact.email, "a@example.com");
+    assert_eq!(contact.name, "Ada");
+}
+
+#[test]
+fn alias_feeds_the_same_field_as_the_rename() {
+    let data = pairs(&[("email_address", "b@example.com"), ("name", "Grace")]);
+    let contact = Contact::deserialize(&data).unwrap();
+    assert_eq!(contact.email, "b@example.com");
+}
+
+fn fallback_nickname() -> String {
+    "anonymous".to_string()
+}
+
+fn shout(s: &str) -> Result<String, String> {
+    Ok(s.to_uppercase())
+}
+
+#[derive(Debug, PartialEq, WebformDeserialize)]
+struct Preferences {
+    #[webformd(default)]
+    nickname: String,
+    #[webformd(default = "fallback_nickname")]
+    display_name: String,
+    #[webformd(from_str)]
+    retries: u32,
+    #[webformd(from_str, default)]
+    max_retries: u32,
+    #[webformd(deserialize_with = "shout")]
+    greeting: String,
+}
+
+#[test]
+fn missing_field_falls_back_to_default() {
+    let data = pairs(&[("display_name", "Ada"), ("retries", "3"), ("greeting", "hi")]);
+    let prefs = Preferences::deserialize(&data).unwrap();
+    assert_eq!(prefs.nickname, String::default());
+    assert_eq!(prefs.display_name, "Ada");
+}
+
+#[test]
+fn missing_field_falls_back_to_custom_default_fn() {
+    let data = pairs(&[("nickname", "ada"), ("retries", "3"), ("greeting", "hi")]);
+    let prefs = Preferences::deserialize(&data).unwrap();
+    assert_eq!(prefs.display_name, "anonymous");
+}
+
+#[test]
+fn from_str_parses_required_scalar_fields() {
+    let data = pairs(&[("display_name", "Ada"), ("retries", "3"), ("greeting", "hi")]);
+    let prefs = Preferences::deserialize(&data).unwrap();
+    assert_eq!(prefs.retries, 3);
+}
+
+#[test]
+fn missing_from_str_field_falls_back_to_default() {
+    let data = pairs(&[("display_name", "Ada"), ("retries", "3"), ("greeting", "hi")]);
+    let prefs = Preferences::deserialize(&data).unwrap();
+    assert_eq!(prefs.max_retries, 0);
+}
+
+#[test]
+fn deserialize_with_runs_without_from_str() {
+    let data = pairs(&[("display_name", "Ada"), ("retries", "3"), ("greeting", "hi")]);
+    let prefs = Preferences::deserialize(&data).unwrap();
+    assert_eq!(prefs.greeting, "HI");
+}
+
+#[derive(Debug, PartialEq, WebformDeserialize)]
+#[webformd(deny_unknown_fields)]
+struct Strict {
+    name: String,
+    age: String,
+}
+
+#[test]
+fn deny_unknown_fields_rejects_typos() {
+    let data = pairs(&[("name", "Ada"), ("agee", "30")]);
+    let err = Strict::deserialize(&data).unwrap_err();
+    assert!(err.contains("Unknown field"), "unexpected error: {err}");
+    assert!(err.contains("Missing required field"), "unexpected error: {err}");
+}
+
+#[test]
+fn errors_accumulate_instead_of_stopping_at_the_first() {
+    let data = pairs(&[("bogus", "x")]);
+    let err = Strict::deserialize(&data).unwrap_err();
+    assert!(err.contains("Missing required field: 'name'"), "{err}");
+    assert!(err.contains("Missing required field: 'age'"), "{err}");
+    assert!(err.contains("Unknown field: 'bogus'"), "{err}");
+}
+
+#[test]
+fn deny_unknown_fields_still_accepts_known_keys() {
+    let data = pairs(&[("name", "Ada"), ("age", "30")]);
+    let strict = Strict::deserialize(&data).unwrap();
+    assert_eq!(
+        strict,
+        Strict { name: "Ada".to_string(), age: "30".to_string() }
+    );
+}
+
+#[derive(Debug, PartialEq, WebformDeserialize, WebformSerialize)]
+struct Survey {
+    name: String,
+    #[webformd(from_str)]
+    scores: Vec<u32>,
+    nickname: Option<String>,
+}
+
+#[test]
+fn round_trips_through_serialize_and_deserialize() {
+    let original = Survey {
+        name: "Ada".to_string(),
+        scores: vec![1, 2, 3],
+        nickname: None,
+    };
+    let serialized = original.serialize();
+    let rebuilt = Survey::deserialize(&serialized).unwrap();
+    assert_eq!(original, rebuilt);
+}
+
+#[derive(Debug, PartialEq, WebformDeserialize)]
+struct Token(String);
+
+#[derive(Debug, PartialEq, WebformDeserialize)]
+struct Pair(
+    #[webformd(key = "first")] String,
+    #[webformd(key = "second", from_str, default)] u32,
+);
+
+#[test]
+fn newtype_struct_binds_its_single_field_by_index() {
+    let data = pairs(&[("0", "secret")]);
+    assert_eq!(Token::deserialize(&data).unwrap(), Token("secret".to_string()));
+}
+
+#[test]
+fn tuple_struct_binds_positional_fields_by_configured_key() {
+    let data = pairs(&[("first", "a"), ("second", "7")]);
+    assert_eq!(Pair::deserialize(&data).unwrap(), Pair("a".to_string(), 7));
+}
+
+#[test]
+fn tuple_struct_falls_back_to_default_on_missing_field() {
+    let data = pairs(&[("first", "a")]);
+    assert_eq!(Pair::deserialize(&data).unwrap(), Pair("a".to_string(), 0));
+}
@@ -10,20 +10,39 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let struct_name = &input.ident; // <-- This is the struct's name!
-    
-    // Check if the input is a named struct, otherwise panic
-    let fields = if let Data::Struct(data_struct) = &input.data {
-        if let Fields::Named(fields) = &data_struct.fields {
-            &fields.named
-        } else {
-            panic!("`#[derive(OursistemDeserialize)]` only supports structs with named fields.");
-        }
+
+    let data_struct = if let Data::Struct(data_struct) = &input.data {
+        data_struct
     } else {
-        panic!("`#[derive(OursistemDeserialize)]` only supports structs.");
+        panic!("`#[derive(WebformDeserialize)]` only supports structs.");
+    };
+
+    // Tuple structs and newtypes (`Fields::Unnamed`) are handled separately below.
+    let fields = match &data_struct.fields {
+        Fields::Named(fields) => &fields.named,
+        Fields::Unnamed(fields) => return deserialize_tuple_struct(struct_name, &input.generics, &input.attrs, fields),
+        Fields::Unit => panic!("`#[derive(WebformDeserialize)]` does not support unit structs."),
     };
 
+    // Container-level `#[webformd(deny_unknown_fields)]`.
+    let mut deny_unknown_fields = false;
+    for attr in input.attrs.iter() {
+        if attr.path.is_ident("webformd") {
+            if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                for nested in meta_list.nested.iter() {
+                    if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                        if path.is_ident("deny_unknown_fields") {
+                            deny_unknown_fields = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     let mut declarations = vec![];
     let mut matches = vec![];
+    let mut pre_struct = vec![];
     let mut assignments = vec![];
 
     let generics = &input.generics;
@@ -38,20 +57,85 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
 
         let temp_var = format_ident!("___{}", field_name_str);
 
-        // Parse the attribute once
-        let from_str_attr = field.attrs.iter().any(|attr| {
+        // Parse the `webformd` attributes once: `from_str`, `rename = "..."`, `alias = "..."`,
+        // `default` / `default = "..."` and `deserialize_with = "..."`.
+        let mut from_str_attr = false;
+        let mut rename: Option<String> = None;
+        let mut aliases: Vec<String> = vec![];
+        let mut has_default = false;
+        let mut default_fn: Option<String> = None;
+        let mut deserialize_with: Option<String> = None;
+
+        for attr in field.attrs.iter() {
             if attr.path.is_ident("webformd") {
                 if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
-                    return meta_list.nested.iter().any(|nested| {
-                        if let NestedMeta::Meta(Meta::Path(path)) = nested {
-                            return path.is_ident("from_str");
+                    for nested in meta_list.nested.iter() {
+                        match nested {
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("from_str") => {
+                                from_str_attr = true;
+                            }
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                                has_default = true;
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    rename = Some(lit.value());
+                                }
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("alias") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    aliases.push(lit.value());
+                                }
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    has_default = true;
+                                    default_fn = Some(lit.value());
+                                }
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("deserialize_with") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    deserialize_with = Some(lit.value());
+                                }
+                            }
+                            _ => {}
                         }
-                        false
-                    });
+                    }
                 }
             }
-            false
-        });
+        }
+
+        // The key(s) that feed this field: the (possibly renamed) canonical name plus any aliases.
+        let canonical_key = rename.unwrap_or_else(|| field_name_str.clone());
+        let mut accepted_keys = vec![canonical_key.clone()];
+        accepted_keys.extend(aliases);
+
+        // How a single `String` value becomes the target type: `deserialize_with = "..."` takes
+        // over from the plain `s.parse()` used by `from_str`.
+        let parse_one = |s_ident: &syn::Ident| -> proc_macro2::TokenStream {
+            if let Some(path) = &deserialize_with {
+                let path: syn::Path = syn::parse_str(path)
+                    .expect("`webformd(deserialize_with = \"...\")` must be a valid function path");
+                quote! { #path(&#s_ident) }
+            } else {
+                quote! { #s_ident.parse() }
+            }
+        };
+
+        // The fallback for a missing required field: `Default::default()`, or `#path()` when
+        // `default = "path"` was given. Shared by the plain and `from_str` required-field branches.
+        let default_expr = || -> proc_macro2::TokenStream {
+            if let Some(fn_path) = &default_fn {
+                let fn_path: syn::Path = syn::parse_str(fn_path)
+                    .expect("`webformd(default = \"...\")` must be a valid function path");
+                quote! { #fn_path() }
+            } else {
+                quote! { Default::default() }
+            }
+        };
+
+        // `deserialize_with` stands on its own (like serde's): it doesn't also require `from_str`.
+        let needs_parsing = from_str_attr || deserialize_with.is_some();
 
         let (is_option, inner_type_of_option) = is_option(field_ty);
         let (is_vec_result, final_ty) = is_vec(field_ty);
@@ -59,32 +143,40 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
         if is_option {
             let (is_inner_vec, inner_final_ty) = is_vec(inner_type_of_option.unwrap());
             if is_inner_vec {
-                if from_str_attr {
-                    // Case: Option<Vec<T>> with from_str
+                if needs_parsing {
+                    // Case: Option<Vec<T>> with from_str/deserialize_with
                     let inner_ty_to_parse = inner_final_ty.unwrap();
                     declarations.push(quote! { let mut #temp_var: Vec<String> = Vec::new(); });
-                    matches.push(quote! {
-                        #field_name_str => { #temp_var.push(value.clone()); }
-                    });
-                    assignments.push(quote! {
-                        #field_name: {
+                    for key in &accepted_keys {
+                        matches.push(quote! {
+                            #key => { #temp_var.push(value.clone()); }
+                        });
+                    }
+                    let parse_expr = parse_one(&format_ident!("s"));
+                    pre_struct.push(quote! {
+                        let #field_name: Option<Vec<#inner_ty_to_parse>> = {
                             let parsed: Result<Vec<#inner_ty_to_parse>, _> = #temp_var
                                 .into_iter()
-                                .map(|s| s.parse())
+                                .map(|s| #parse_expr)
                                 .collect();
-                            let final_vec = match parsed {
-                                Ok(v) => v,
-                                Err(e) => return Err(e.to_string()),
-                            };
-                            if final_vec.is_empty() { None } else { Some(final_vec) }
-                        },
+                            match parsed {
+                                Ok(v) => if v.is_empty() { None } else { Some(v) },
+                                Err(e) => {
+                                    errors.push(format!("{}: {}", #canonical_key, e.to_string()));
+                                    None
+                                }
+                            }
+                        };
                     });
+                    assignments.push(quote! { #field_name: #field_name, });
                 } else {
                     // Case: Option<Vec<String>>
                     declarations.push(quote! { let mut #temp_var: Vec<String> = Vec::new(); });
-                    matches.push(quote! {
-                        #field_name_str => { #temp_var.push(value.clone()); }
-                    });
+                    for key in &accepted_keys {
+                        matches.push(quote! {
+                            #key => { #temp_var.push(value.clone()); }
+                        });
+                    }
                     assignments.push(quote! {
                         #field_name: if #temp_var.is_empty() { None } else { Some(#temp_var) },
                     });
@@ -92,61 +184,133 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
             } else {
                 // Case: Option<T> (without Vec)
                 declarations.push(quote! { let mut #field_name: Option<Option<String>> = None; });
-                matches.push(quote! { #field_name_str => { #field_name = Some(Some(value.clone())); } });
+                for key in &accepted_keys {
+                    matches.push(quote! { #key => { #field_name = Some(Some(value.clone())); } });
+                }
                 assignments.push(quote! { #field_name: #field_name.flatten(), });
             }
         } else if is_vec_result {
-            if from_str_attr {
-                // This part is for `Vec<T>` with `from_str`. The assignment is correct here.
+            if needs_parsing {
+                // This part is for `Vec<T>` with `from_str`/`deserialize_with`.
                 let final_ty = final_ty.unwrap();
                 declarations.push(quote! { let mut #temp_var: Vec<String> = Vec::new(); });
-                matches.push(quote! {
-                    #field_name_str => { #temp_var.push(value.clone()); }
-                });
-                assignments.push(quote! {
-                    #field_name: {
-                        let parsed: Result<Vec<#final_ty>, _> = #temp_var.into_iter().map(|s| s.parse()).collect();
+                for key in &accepted_keys {
+                    matches.push(quote! {
+                        #key => { #temp_var.push(value.clone()); }
+                    });
+                }
+                let parse_expr = parse_one(&format_ident!("s"));
+                pre_struct.push(quote! {
+                    let #field_name: Vec<#final_ty> = {
+                        let parsed: Result<Vec<#final_ty>, _> = #temp_var.into_iter().map(|s| #parse_expr).collect();
                         match parsed {
                             Ok(v) => v,
-                            Err(e) => return Err(e.to_string()),
+                            Err(e) => {
+                                errors.push(format!("{}: {}", #canonical_key, e.to_string()));
+                                Vec::new()
+                            }
                         }
-                    },
+                    };
                 });
+                assignments.push(quote! { #field_name: #field_name, });
             } else {
-                // This is the section you need to fix.
                 // The assignment should be direct, without `ok_or_else`.
                 declarations.push(quote! { let mut #temp_var: Vec<String> = Vec::new(); });
-                matches.push(quote! {
-                    #field_name_str => { #temp_var.push(value.clone()); }
-                });
-                // Corrected assignment:
+                for key in &accepted_keys {
+                    matches.push(quote! {
+                        #key => { #temp_var.push(value.clone()); }
+                    });
+                }
                 assignments.push(quote! { #field_name: #temp_var, });
             }
         } else {
-            // This is the part for a required `String`, where `ok_or_else` is correct.
-            if from_str_attr {
-                // ...
+            // This is the part for a required field parsed from a single string value.
+            if needs_parsing {
+                // Case: required scalar `T` parsed via `from_str` (or `deserialize_with`).
+                declarations.push(quote! { let mut #field_name: Option<String> = None; });
+                for key in &accepted_keys {
+                    matches.push(quote! {
+                        #key => { #field_name = Some(value.clone()); }
+                    });
+                }
+                let parse_expr = parse_one(&format_ident!("s"));
+                let none_arm = if has_default {
+                    let default_expr = default_expr();
+                    quote! { #default_expr }
+                } else {
+                    quote! {
+                        {
+                            errors.push(format!("Missing required field: '{}'", #canonical_key));
+                            Default::default()
+                        }
+                    }
+                };
+                pre_struct.push(quote! {
+                    let #field_name: #field_ty = match #field_name {
+                        Some(s) => match #parse_expr {
+                            Ok(v) => v,
+                            Err(e) => {
+                                errors.push(format!("{}: {}", #canonical_key, e.to_string()));
+                                Default::default()
+                            }
+                        },
+                        None => #none_arm,
+                    };
+                });
+                assignments.push(quote! { #field_name: #field_name, });
             } else {
                 declarations.push(quote::quote! { let mut #field_name: Option<String> = None; });
-                matches.push(quote::quote! {
-                    #field_name_str => { #field_name = Some(value.clone()); }
-                });
-                assignments.push(quote::quote! { #field_name: #field_name.ok_or_else(|| format!("Missing required field: '{}'", #field_name_str))?, });
+                for key in &accepted_keys {
+                    matches.push(quote::quote! {
+                        #key => { #field_name = Some(value.clone()); }
+                    });
+                }
+                if has_default {
+                    let default_expr = default_expr();
+                    assignments.push(quote::quote! {
+                        #field_name: match #field_name {
+                            Some(v) => v,
+                            None => #default_expr,
+                        },
+                    });
+                } else {
+                    pre_struct.push(quote::quote! {
+                        let #field_name: String = match #field_name {
+                            Some(v) => v,
+                            None => {
+                                errors.push(format!("Missing required field: '{}'", #canonical_key));
+                                String::new()
+                            }
+                        };
+                    });
+                    assignments.push(quote::quote! { #field_name: #field_name, });
+                }
             }
         }
     }
 
+    let catch_all = if deny_unknown_fields {
+        quote! { other => { errors.push(format!("Unknown field: '{}'", other)); } }
+    } else {
+        quote! { _ => {} }
+    };
+
     // This is the crucial part: using `#struct_name` to make the implementation generic.
     let expanded = quote! {
         impl #impl_generics webformd::WebFomData for #struct_name #ty_generics #where_clause {
             fn deserialize(data: &Vec<(String, String)>) -> Result<Self, String> {
                 #(#declarations)*
+                let mut errors: Vec<String> = Vec::new();
                 for (key, value) in data {
                     match key.as_str() {
                         #(#matches)*
-                        _ => {}
+                        #catch_all
                     }
                 }
+                #(#pre_struct)*
+                if !errors.is_empty() {
+                    return Err(errors.join("; "));
+                }
                 let s = #struct_name {
                     #(#assignments)*
                 };
@@ -158,6 +322,265 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+// Tuple structs / newtypes (`struct Token(String);`) bind each positional field to a key —
+// `#[webformd(key = "...")]`, defaulting to the field's index — instead of its (nonexistent)
+// identifier, and build `Self(...)` instead of `Self { field: ... }`.
+fn deserialize_tuple_struct(
+    struct_name: &syn::Ident,
+    generics: &syn::Generics,
+    container_attrs: &[syn::Attribute],
+    fields: &syn::FieldsUnnamed,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut deny_unknown_fields = false;
+    for attr in container_attrs.iter() {
+        if attr.path.is_ident("webformd") {
+            if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                for nested in meta_list.nested.iter() {
+                    if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                        if path.is_ident("deny_unknown_fields") {
+                            deny_unknown_fields = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut declarations = vec![];
+    let mut matches = vec![];
+    let mut pre_struct = vec![];
+    let mut values = vec![];
+
+    for (index, field) in fields.unnamed.iter().enumerate() {
+        let field_ty = &field.ty;
+
+        let mut key: Option<String> = None;
+        let mut from_str_attr = false;
+        let mut deserialize_with: Option<String> = None;
+        let mut has_default = false;
+        let mut default_fn: Option<String> = None;
+
+        for attr in field.attrs.iter() {
+            if attr.path.is_ident("webformd") {
+                if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                    for nested in meta_list.nested.iter() {
+                        match nested {
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("from_str") => {
+                                from_str_attr = true;
+                            }
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                                has_default = true;
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("key") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    key = Some(lit.value());
+                                }
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("deserialize_with") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    deserialize_with = Some(lit.value());
+                                }
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    has_default = true;
+                                    default_fn = Some(lit.value());
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        let key_str = key.unwrap_or_else(|| index.to_string());
+        let raw_var = format_ident!("___field{}", index);
+        let val_var = format_ident!("___val{}", index);
+
+        declarations.push(quote! { let mut #raw_var: Option<String> = None; });
+        matches.push(quote! { #key_str => { #raw_var = Some(value.clone()); } });
+
+        // `deserialize_with` stands on its own (like serde's): it doesn't also require `from_str`.
+        let needs_parsing = from_str_attr || deserialize_with.is_some();
+
+        let parsed_expr = if needs_parsing {
+            let parse_call = if let Some(path) = &deserialize_with {
+                let path: syn::Path = syn::parse_str(path)
+                    .expect("`webformd(deserialize_with = \"...\")` must be a valid function path");
+                quote! { #path(&s) }
+            } else {
+                quote! { s.parse() }
+            };
+            quote! {
+                match #parse_call {
+                    Ok(v) => v,
+                    Err(e) => {
+                        errors.push(format!("{}: {}", #key_str, e.to_string()));
+                        Default::default()
+                    }
+                }
+            }
+        } else {
+            quote! { s }
+        };
+
+        let none_arm = if has_default {
+            if let Some(fn_path) = &default_fn {
+                let fn_path: syn::Path = syn::parse_str(fn_path)
+                    .expect("`webformd(default = \"...\")` must be a valid function path");
+                quote! { #fn_path() }
+            } else {
+                quote! { Default::default() }
+            }
+        } else {
+            quote! {
+                {
+                    errors.push(format!("Missing required field: '{}'", #key_str));
+                    Default::default()
+                }
+            }
+        };
+
+        pre_struct.push(quote! {
+            let #val_var: #field_ty = match #raw_var {
+                Some(s) => #parsed_expr,
+                None => #none_arm,
+            };
+        });
+
+        values.push(quote! { #val_var });
+    }
+
+    let catch_all = if deny_unknown_fields {
+        quote! { other => { errors.push(format!("Unknown field: '{}'", other)); } }
+    } else {
+        quote! { _ => {} }
+    };
+
+    let expanded = quote! {
+        impl #impl_generics webformd::WebFomData for #struct_name #ty_generics #where_clause {
+            fn deserialize(data: &Vec<(String, String)>) -> Result<Self, String> {
+                #(#declarations)*
+                let mut errors: Vec<String> = Vec::new();
+                for (key, value) in data {
+                    match key.as_str() {
+                        #(#matches)*
+                        #catch_all
+                    }
+                }
+                #(#pre_struct)*
+                if !errors.is_empty() {
+                    return Err(errors.join("; "));
+                }
+                Ok(#struct_name(#(#values),*))
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+
+#[proc_macro_derive(WebformSerialize, attributes(webformd))]
+pub fn serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    // Check if the input is a named struct, otherwise panic
+    let fields = if let Data::Struct(data_struct) = &input.data {
+        if let Fields::Named(fields) = &data_struct.fields {
+            &fields.named
+        } else {
+            panic!("`#[derive(WebformSerialize)]` only supports structs with named fields.");
+        }
+    } else {
+        panic!("`#[derive(WebformSerialize)]` only supports structs.");
+    };
+
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut pushes = vec![];
+
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_ty = &field.ty;
+        let field_name_str = field_name.to_string();
+
+        // Serialization only needs to know the output key, i.e. `rename` (aliases are
+        // extra *input* keys on deserialize and don't affect what gets emitted here).
+        let mut rename: Option<String> = None;
+        for attr in field.attrs.iter() {
+            if attr.path.is_ident("webformd") {
+                if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                    for nested in meta_list.nested.iter() {
+                        if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                            if nv.path.is_ident("rename") {
+                                if let syn::Lit::Str(lit) = &nv.lit {
+                                    rename = Some(lit.value());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let canonical_key = rename.unwrap_or(field_name_str);
+
+        let (is_option, inner_type_of_option) = is_option(field_ty);
+        let (is_vec_result, _) = is_vec(field_ty);
+
+        if is_option {
+            let inner_ty = inner_type_of_option.unwrap();
+            let (is_inner_vec, _) = is_vec(inner_ty);
+            if is_inner_vec {
+                // Option<Vec<T>>: one pair per element when present, nothing when `None`.
+                pushes.push(quote! {
+                    if let Some(ref ___items) = self.#field_name {
+                        for ___item in ___items {
+                            result.push((#canonical_key.to_string(), ___item.to_string()));
+                        }
+                    }
+                });
+            } else {
+                // Option<T>: one pair when present, nothing when `None`.
+                pushes.push(quote! {
+                    if let Some(ref ___v) = self.#field_name {
+                        result.push((#canonical_key.to_string(), ___v.to_string()));
+                    }
+                });
+            }
+        } else if is_vec_result {
+            // Vec<T>: one pair per element, sharing the same key, as repeated form fields do.
+            pushes.push(quote! {
+                for ___item in &self.#field_name {
+                    result.push((#canonical_key.to_string(), ___item.to_string()));
+                }
+            });
+        } else {
+            // Scalar field: a single pair via `ToString`.
+            pushes.push(quote! {
+                result.push((#canonical_key.to_string(), self.#field_name.to_string()));
+            });
+        }
+    }
+
+    let expanded = quote! {
+        impl #impl_generics webformd::WebFormSerialize for #struct_name #ty_generics #where_clause {
+            fn serialize(&self) -> Vec<(String, String)> {
+                let mut result = Vec::new();
+                #(#pushes)*
+                result
+            }
+        }
+    };
+
+    expanded.into()
+}
 
 fn is_option(ty: &syn::Type) -> (bool, Option<&syn::Type>) {
     if let syn::Type::Path(type_path) = ty {